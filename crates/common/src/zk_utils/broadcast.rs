@@ -0,0 +1,324 @@
+//! Routes simulated `forge script` broadcasts through the zkSync signer.
+//!
+//! There is no zkSync equivalent of a plain EVM `CREATE`/`CALL` transaction: every contract
+//! deployment goes through the `ContractDeployer` system contract, and every transaction needs
+//! zkSync-specific fields (`factory_deps`, paymaster params, `gas_per_pubdata_limit`) that a
+//! simulated EVM transaction simply doesn't carry. When `config.zksync` is set, the executor
+//! intercepts the transactions a script simulation produced and re-emits them in zkSync's shape
+//! before they're signed and broadcast.
+
+use crate::zk_utils::{
+    factory_deps::resolve_factory_deps,
+    fee::{estimate_fee, ZkFeeEstimate},
+    DualCompiledContract,
+};
+use alloy_primitives::{Address, Bytes, U256 as AlloyU256};
+use eyre::{eyre, Result};
+use std::collections::HashMap;
+use zksync_basic_types::U256;
+use zksync_types::{CONTRACT_DEPLOYER_ADDRESS, H256};
+use zksync_web3_rs::eip712::{Eip712Meta, Eip712TransactionRequest};
+use zksync_web3_rs::signers::{PrivateKeySigner, Signer};
+use zksync_web3_rs::zks_wallet::{CallRequest, ZKSWallet};
+
+/// A simulated transaction produced while executing a `forge script`, destined for rewriting
+/// into zkSync's calling convention.
+#[derive(Debug, Clone)]
+pub enum SimulatedZkTransaction {
+    /// A `CREATE`/`CREATE2` the simulation performed; `init_code` is the EVM init code the
+    /// simulator used (creation bytecode followed by ABI-encoded constructor args), which must
+    /// be swapped for the matching [`DualCompiledContract`]'s zkSync bytecode.
+    Deploy {
+        sender: Address,
+        init_code: Bytes,
+        value: AlloyU256,
+        /// Gas limit the simulation used for this transaction; the starting point for
+        /// [`estimate_fee`]'s fallback if `zks_estimateFee` can't be reached.
+        gas_limit: AlloyU256,
+        /// Gas price the simulation used for this transaction; the starting point for
+        /// [`estimate_fee`]'s fallback if `zks_estimateFee` can't be reached.
+        gas_price: AlloyU256,
+    },
+    /// A plain `CALL` to an already-deployed contract.
+    Call {
+        sender: Address,
+        to: Address,
+        calldata: Bytes,
+        value: AlloyU256,
+        /// Gas limit the simulation used for this transaction; the starting point for
+        /// [`estimate_fee`]'s fallback if `zks_estimateFee` can't be reached.
+        gas_limit: AlloyU256,
+        /// Gas price the simulation used for this transaction; the starting point for
+        /// [`estimate_fee`]'s fallback if `zks_estimateFee` can't be reached.
+        gas_price: AlloyU256,
+    },
+}
+
+/// A zkSync transaction ready to be signed and broadcast, with all zkSync-only fields filled in.
+#[derive(Debug, Clone)]
+pub struct ZkTransactionRequest {
+    /// Transaction sender.
+    pub from: Address,
+    /// Transaction recipient; the `ContractDeployer` for deployments.
+    pub to: Address,
+    /// Calldata; a `ContractDeployer.create`/`create2` call for deployments.
+    pub data: Bytes,
+    /// Transaction value.
+    pub value: AlloyU256,
+    /// For a deployment, the deployed contract's own zkSync bytecode; the bytecode of contracts
+    /// it deploys in turn is not included. Empty for calls.
+    pub factory_deps: Vec<Vec<u8>>,
+    /// Fee parameters from [`estimate_fee`], including `gas_per_pubdata_limit`.
+    pub fee: ZkFeeEstimate,
+}
+
+/// Rewrites the transactions a `forge script` simulation produced into zkSync transactions,
+/// swapping each deployment's EVM bytecode for the matching zkSync bytecode and filling in the
+/// fields zkSync requires, using `registry` to find each contract's [`DualCompiledContract`].
+pub async fn rewrite_for_zksync(
+    rpc_url: &Option<String>,
+    transactions: &[SimulatedZkTransaction],
+    registry: &HashMap<H256, DualCompiledContract>,
+) -> Result<Vec<ZkTransactionRequest>> {
+    let mut requests = Vec::with_capacity(transactions.len());
+    for tx in transactions {
+        requests.push(rewrite_one(rpc_url, tx, registry).await?);
+    }
+    Ok(requests)
+}
+
+async fn rewrite_one(
+    rpc_url: &Option<String>,
+    tx: &SimulatedZkTransaction,
+    registry: &HashMap<H256, DualCompiledContract>,
+) -> Result<ZkTransactionRequest> {
+    match tx {
+        SimulatedZkTransaction::Deploy {
+            sender,
+            init_code,
+            value,
+            gas_limit,
+            gas_price,
+        } => {
+            let (contract, constructor_args) = find_contract_for_init_code(init_code, registry)?;
+
+            // Only the deployed contract's own bytecode is attached as a factory dependency (it
+            // must be registered under its zk bytecode hash); contracts it deploys are not added.
+            let factory_deps = resolve_factory_deps(&[contract.zk_bytecode_hash], registry)?;
+
+            let data =
+                encode_contract_deployer_create(contract.zk_bytecode_hash, &constructor_args);
+            let to = Address::from_slice(CONTRACT_DEPLOYER_ADDRESS.as_bytes());
+            let fee = estimate_fee(
+                rpc_url,
+                &typed_transaction(*sender, to, &data, *value, factory_deps.clone()),
+                to_zk_u256(*gas_price),
+                to_zk_u256(*gas_limit),
+            )
+            .await?;
+
+            Ok(ZkTransactionRequest {
+                from: *sender,
+                to,
+                data: data.into(),
+                value: *value,
+                factory_deps,
+                fee,
+            })
+        }
+        SimulatedZkTransaction::Call {
+            sender,
+            to,
+            calldata,
+            value,
+            gas_limit,
+            gas_price,
+        } => {
+            let fee = estimate_fee(
+                rpc_url,
+                &typed_transaction(*sender, *to, calldata, *value, Vec::new()),
+                to_zk_u256(*gas_price),
+                to_zk_u256(*gas_limit),
+            )
+            .await?;
+
+            Ok(ZkTransactionRequest {
+                from: *sender,
+                to: *to,
+                data: calldata.clone(),
+                value: *value,
+                factory_deps: Vec::new(),
+                fee,
+            })
+        }
+    }
+}
+
+/// Finds the [`DualCompiledContract`] whose EVM creation bytecode is a prefix of `init_code`,
+/// returning it along with the trailing bytes (the ABI-encoded constructor arguments the
+/// simulator appended). A real `CREATE`'s `init_code` is `creation_bytecode ++
+/// abi.encode(constructor_args)`, so matching on the bare creation bytecode (rather than the
+/// full `init_code`) is required for any contract that takes constructor arguments.
+///
+/// Contracts with empty `evm_bytecode` (interfaces, abstract contracts) are skipped: `slice`'s
+/// `starts_with` is vacuously true for an empty needle, so without this an empty-bytecode
+/// registry entry would match every deployment.
+fn find_contract_for_init_code<'a>(
+    init_code: &[u8],
+    registry: &'a HashMap<H256, DualCompiledContract>,
+) -> Result<(&'a DualCompiledContract, Vec<u8>)> {
+    registry
+        .values()
+        .filter(|contract| !contract.evm_bytecode.is_empty())
+        .find(|contract| init_code.starts_with(&contract.evm_bytecode))
+        .map(|contract| (contract, init_code[contract.evm_bytecode.len()..].to_vec()))
+        .ok_or_else(|| {
+            eyre!(
+                "no dual-compiled contract's bytecode is a prefix of the simulated init code; \
+                 was it built with `zksync = true`?"
+            )
+        })
+}
+
+/// Converts an `alloy_primitives::U256` to the `zksync_basic_types::U256` the rest of the zkSync
+/// tooling (fee estimation, signer) works with.
+fn to_zk_u256(value: AlloyU256) -> U256 {
+    U256::from_little_endian(&value.as_le_bytes::<32>())
+}
+
+/// Builds the EIP-712 transaction request `zks_estimateFee` expects. `factory_deps` must be
+/// attached here (rather than left for the signer to add later): for a deployment the calldata
+/// only carries the bytecode *hash*, so the node needs the actual bytecode bytes to know what
+/// it's pricing pubdata/gas for, and `gas_per_pubdata_limit`/the rest of the fee fields are the
+/// only things genuinely left for the node to estimate.
+fn typed_transaction(
+    from: Address,
+    to: Address,
+    data: &[u8],
+    value: AlloyU256,
+    factory_deps: Vec<Vec<u8>>,
+) -> Eip712TransactionRequest {
+    Eip712TransactionRequest::new()
+        .from(ethers::types::H160::from(from.0 .0))
+        .to(ethers::types::H160::from(to.0 .0))
+        .data(data.to_vec())
+        .value(ethers::types::U256::from_little_endian(
+            &value.as_le_bytes::<32>(),
+        ))
+        .custom_data(Eip712Meta::new().factory_deps(factory_deps))
+}
+
+/// ABI-encodes a `ContractDeployer.create(bytes32,bytes32,bytes)` call with a zero salt, the
+/// given bytecode hash, and `constructor_args` as the trailing input.
+fn encode_contract_deployer_create(bytecode_hash: H256, constructor_args: &[u8]) -> Vec<u8> {
+    use crate::zk_utils::constructor_args::ContractDeployer;
+    use alloy_sol_types::SolCall;
+
+    ContractDeployer::createCall {
+        salt: [0u8; 32].into(),
+        bytecodeHash: bytecode_hash.to_fixed_bytes().into(),
+        input: constructor_args.to_vec().into(),
+    }
+    .abi_encode()
+}
+
+/// Signs and broadcasts `request` using the wallet built from `signer`.
+pub async fn broadcast(
+    signer: Signer<PrivateKeySigner>,
+    rpc_url: &str,
+    request: ZkTransactionRequest,
+) -> Result<H256> {
+    let wallet = ZKSWallet::new(signer, None, rpc_url.parse().ok(), None)?;
+
+    let call_request = CallRequest::new()
+        .to(request.to)
+        .data(request.data.to_vec())
+        .value(request.value)
+        .gas(request.fee.gas_limit)
+        .gas_price(request.fee.max_fee_per_gas)
+        .gas_per_pubdata_byte_limit(request.fee.gas_per_pubdata_limit);
+
+    let receipt = wallet
+        .send_transaction_with_factory_deps(call_request, request.factory_deps)
+        .await?;
+    Ok(receipt.transaction_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract(name: &str, evm_bytecode: Vec<u8>) -> DualCompiledContract {
+        DualCompiledContract {
+            name: name.to_string(),
+            evm_bytecode,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn finds_contract_and_splits_constructor_args() {
+        let mut registry = HashMap::new();
+        let evm_bytecode = vec![0xaa, 0xbb, 0xcc];
+        registry.insert(H256::zero(), contract("Foo", evm_bytecode.clone()));
+
+        let constructor_args = vec![0x01, 0x02, 0x03, 0x04];
+        let mut init_code = evm_bytecode.clone();
+        init_code.extend_from_slice(&constructor_args);
+
+        let (found, args) = find_contract_for_init_code(&init_code, &registry).unwrap();
+        assert_eq!(found.name, "Foo");
+        assert_eq!(args, constructor_args);
+    }
+
+    #[test]
+    fn skips_empty_bytecode_entries_when_matching() {
+        let mut registry = HashMap::new();
+        registry.insert(H256::zero(), contract("IFoo", Vec::new()));
+
+        let evm_bytecode = vec![0xaa, 0xbb, 0xcc];
+        registry.insert(H256::repeat_byte(1), contract("Foo", evm_bytecode.clone()));
+
+        let constructor_args = vec![0x01, 0x02];
+        let mut init_code = evm_bytecode.clone();
+        init_code.extend_from_slice(&constructor_args);
+
+        let (found, args) = find_contract_for_init_code(&init_code, &registry).unwrap();
+        assert_eq!(found.name, "Foo");
+        assert_eq!(args, constructor_args);
+    }
+
+    #[test]
+    fn errors_when_only_empty_bytecode_entries_exist() {
+        let mut registry = HashMap::new();
+        registry.insert(H256::zero(), contract("IFoo", Vec::new()));
+
+        let err = find_contract_for_init_code(&[0x01, 0x02], &registry).unwrap_err();
+        assert!(err.to_string().contains("no dual-compiled contract"));
+    }
+
+    #[test]
+    fn errors_when_no_contract_bytecode_is_a_prefix() {
+        let registry = HashMap::new();
+        let err = find_contract_for_init_code(&[0x01, 0x02], &registry).unwrap_err();
+        assert!(err.to_string().contains("no dual-compiled contract"));
+    }
+
+    #[test]
+    fn to_zk_u256_round_trips_small_values() {
+        assert_eq!(to_zk_u256(AlloyU256::from(12345u64)), U256::from(12345u64));
+    }
+
+    #[test]
+    fn deploy_estimation_request_carries_factory_deps() {
+        let factory_deps = vec![vec![0xde, 0xad], vec![0xbe, 0xef]];
+        let tx = typed_transaction(
+            Address::ZERO,
+            Address::ZERO,
+            &[],
+            AlloyU256::ZERO,
+            factory_deps.clone(),
+        );
+        assert_eq!(tx.custom_data.factory_deps, factory_deps);
+    }
+}
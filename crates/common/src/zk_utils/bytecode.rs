@@ -0,0 +1,109 @@
+//! Validity and size checks for zksolc-compiled bytecode.
+//!
+//! zkSync's bytecode-hash format requires each deployed bytecode's length to be a multiple of 32
+//! bytes, with an *odd* number of 32-byte words; a contract that doesn't satisfy this invariant
+//! reverts on deployment with no further diagnostic from the node. This module checks that
+//! invariant at build time, and separately flags contracts that exceed zkSync's bytecode size
+//! ceiling (which `forge build` reports as a warning), mirroring the EVM path's Spurious Dragon
+//! 24KB check.
+
+use eyre::{eyre, Result};
+use serde::Serialize;
+
+/// zkSync's maximum deployed contract bytecode size, in bytes.
+///
+/// Unlike EVM's EIP-170 24,576 byte limit, zkSync's limit comes from the maximum number of
+/// 32-byte words the bootloader will charge/account for in a single contract: 2^16 words.
+pub const MAX_ZKSYNC_BYTECODE_SIZE: usize = (1 << 16) * 32;
+
+/// Size of a single compiled contract's zkSync deployed bytecode, for `--sizes`/`--format-json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ZkContractBytecodeSize {
+    /// Contract name.
+    pub name: String,
+    /// Deployed bytecode size, in bytes.
+    pub size: usize,
+    /// Whether `size` exceeds [`MAX_ZKSYNC_BYTECODE_SIZE`].
+    pub size_exceeded: bool,
+}
+
+/// Validates that `deployed_bytecode`'s length is a multiple of 32 bytes and has an odd number of
+/// 32-byte words, as zkSync's bytecode-hash format requires. Contracts violating this invariant
+/// revert on deployment.
+///
+/// Empty bytecode (abstract contracts, interfaces) is not actually deployed, so it is exempt from
+/// this check, mirroring the EVM size check's treatment of the same artifacts.
+pub fn validate_bytecode_words(name: &str, deployed_bytecode: &[u8]) -> Result<()> {
+    if deployed_bytecode.is_empty() {
+        return Ok(());
+    }
+
+    if deployed_bytecode.len() % 32 != 0 {
+        return Err(eyre!(
+            "`{name}`'s zksolc deployed bytecode length ({} bytes) is not a multiple of 32 bytes; \
+             this contract cannot be deployed on zkSync",
+            deployed_bytecode.len()
+        ));
+    }
+
+    let words = deployed_bytecode.len() / 32;
+    if words % 2 == 0 {
+        return Err(eyre!(
+            "`{name}`'s zksolc deployed bytecode has an even number of 32-byte words ({words}); \
+             zkSync's bytecode-hash format requires an odd number of words, so this contract \
+             cannot be deployed"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks `deployed_bytecode`'s size against [`MAX_ZKSYNC_BYTECODE_SIZE`], returning a summary
+/// suitable for `--sizes`/`--format-json` output.
+pub fn check_bytecode_size(name: &str, deployed_bytecode: &[u8]) -> ZkContractBytecodeSize {
+    let size = deployed_bytecode.len();
+    ZkContractBytecodeSize {
+        name: name.to_string(),
+        size,
+        size_exceeded: size > MAX_ZKSYNC_BYTECODE_SIZE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_bytecode_is_exempt() {
+        validate_bytecode_words("Interface", &[]).unwrap();
+    }
+
+    #[test]
+    fn odd_word_count_is_valid() {
+        validate_bytecode_words("Foo", &[0u8; 32 * 3]).unwrap();
+    }
+
+    #[test]
+    fn even_word_count_is_rejected() {
+        let err = validate_bytecode_words("Foo", &[0u8; 32 * 2]).unwrap_err();
+        assert!(err.to_string().contains("even number"));
+    }
+
+    #[test]
+    fn non_multiple_of_32_is_rejected() {
+        let err = validate_bytecode_words("Foo", &[0u8; 31]).unwrap_err();
+        assert!(err.to_string().contains("not a multiple of 32"));
+    }
+
+    #[test]
+    fn flags_oversized_bytecode() {
+        let size = check_bytecode_size("Foo", &vec![0u8; MAX_ZKSYNC_BYTECODE_SIZE + 1]);
+        assert!(size.size_exceeded);
+    }
+
+    #[test]
+    fn does_not_flag_bytecode_at_the_limit() {
+        let size = check_bytecode_size("Foo", &vec![0u8; MAX_ZKSYNC_BYTECODE_SIZE]);
+        assert!(!size.size_exceeded);
+    }
+}
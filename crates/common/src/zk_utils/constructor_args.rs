@@ -0,0 +1,164 @@
+//! Recovery of constructor arguments for already-deployed zkSync contracts, for use with
+//! `--guess-constructor-args` on the zkSync verification path.
+//!
+//! On zkSync, contracts are never deployed via a plain `CREATE`/`CREATE2` transaction to the
+//! target address. Instead the deploy transaction calls the `ContractDeployer` system contract,
+//! whose calldata encodes the bytecode hash to deploy plus an ABI-encoded tail holding the
+//! constructor arguments. Recovering the arguments means fetching that transaction, decoding the
+//! `ContractDeployer` call, and isolating the trailing blob.
+
+use crate::zk_utils::{get_rpc_url, DualCompiledContract};
+use alloy_dyn_abi::DynSolValue;
+use alloy_sol_types::{sol, SolCall};
+use eyre::{eyre, Result};
+use zksync_types::CONTRACT_DEPLOYER_ADDRESS;
+use zksync_web3_rs::providers::{Http, Middleware, Provider};
+use zksync_web3_rs::types::H256;
+
+sol! {
+    /// The zkSync `ContractDeployer` system contract interface, re-used by the scripting
+    /// broadcast path to build deployment calldata.
+    pub interface ContractDeployer {
+        function create(bytes32 salt, bytes32 bytecodeHash, bytes calldata input) external returns (address);
+        function create2(bytes32 salt, bytes32 bytecodeHash, bytes calldata input) external returns (address);
+        function create2Account(bytes32 salt, bytes32 bytecodeHash, bytes calldata input, uint8 aaVersion) external returns (address);
+    }
+}
+
+/// Recovers the constructor arguments used to deploy `contract`, by decoding the
+/// `ContractDeployer` call made in `creation_tx_hash`.
+///
+/// The recovered bytes are validated by ABI-decoding them against the contract's constructor
+/// signature (taken from `contract.abi`); if that fails, `None` is returned so callers can fall
+/// back to requiring the user to pass `--constructor-args` explicitly. An error is returned if
+/// the transaction is not found, does not call `ContractDeployer`, or its calldata matches none
+/// of the `ContractDeployer` functions.
+pub async fn guess_constructor_args(
+    rpc_url: &Option<String>,
+    creation_tx_hash: H256,
+    contract: &DualCompiledContract,
+) -> Result<Option<Vec<u8>>> {
+    let rpc_url = get_rpc_url(rpc_url)?;
+    let provider = Provider::<Http>::try_from(rpc_url.as_str())?;
+
+    let tx = provider
+        .get_transaction(creation_tx_hash)
+        .await?
+        .ok_or_else(|| eyre!("creation transaction {creation_tx_hash:?} not found"))?;
+
+    if tx.to != Some(CONTRACT_DEPLOYER_ADDRESS.into()) {
+        return Err(eyre!(
+            "transaction {creation_tx_hash:?} does not call the ContractDeployer system contract"
+        ));
+    }
+
+    let candidates = decode_deployer_calldata(&tx.input)?;
+    Ok(pick_matching_candidate(candidates, contract))
+}
+
+/// Decodes a `ContractDeployer.create`/`create2`/`create2Account` call and returns its `input`
+/// argument (the constructor-arg blob) as the only candidate; no other offsets are tried.
+fn decode_deployer_calldata(calldata: &[u8]) -> Result<Vec<Vec<u8>>> {
+    if let Ok(call) = ContractDeployer::createCall::abi_decode(calldata, true) {
+        return Ok(vec![call.input]);
+    }
+    if let Ok(call) = ContractDeployer::create2Call::abi_decode(calldata, true) {
+        return Ok(vec![call.input]);
+    }
+    if let Ok(call) = ContractDeployer::create2AccountCall::abi_decode(calldata, true) {
+        return Ok(vec![call.input]);
+    }
+
+    Err(eyre!(
+        "calldata does not match any known ContractDeployer function"
+    ))
+}
+
+/// Of the candidate constructor-arg blobs, returns the one that round-trips exactly when
+/// re-encoded against `contract`'s constructor signature, preferring an exact byte match if more
+/// than one candidate decodes cleanly.
+///
+/// Most contracts have no explicit constructor, in which case `contract.abi.constructor()` is
+/// `None`; that means the constructor takes no arguments, not that no candidate can match, so the
+/// expected encoding is simply the empty blob.
+fn pick_matching_candidate(
+    candidates: Vec<Vec<u8>>,
+    contract: &DualCompiledContract,
+) -> Option<Vec<u8>> {
+    let Some(constructor) = contract.abi.constructor() else {
+        return candidates
+            .into_iter()
+            .find(|candidate| candidate.is_empty());
+    };
+    let types: Vec<_> = constructor.inputs.iter().map(|input| &input.ty).collect();
+
+    candidates.into_iter().find(|candidate| {
+        let Ok(decoded) = decode_constructor_args(&types, candidate) else {
+            return false;
+        };
+        let Ok(reencoded) = encode_constructor_args(&decoded) else {
+            return false;
+        };
+        reencoded == *candidate
+    })
+}
+
+fn decode_constructor_args(types: &[&String], data: &[u8]) -> Result<Vec<DynSolValue>> {
+    let resolved = types
+        .iter()
+        .map(|ty| ty.parse::<alloy_dyn_abi::DynSolType>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| eyre!("invalid constructor type: {e}"))?;
+    let tuple = alloy_dyn_abi::DynSolType::Tuple(resolved);
+    let decoded = tuple
+        .abi_decode_params(data)
+        .map_err(|e| eyre!("constructor args did not decode: {e}"))?;
+    match decoded {
+        DynSolValue::Tuple(values) => Ok(values),
+        other => Ok(vec![other]),
+    }
+}
+
+fn encode_constructor_args(values: &[DynSolValue]) -> Result<Vec<u8>> {
+    Ok(DynSolValue::Tuple(values.to_vec()).abi_encode_params())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_calldata(input: Vec<u8>) -> Vec<u8> {
+        ContractDeployer::createCall {
+            salt: [0u8; 32].into(),
+            bytecodeHash: [0u8; 32].into(),
+            input: input.into(),
+        }
+        .abi_encode()
+    }
+
+    #[test]
+    fn decodes_create_calldata() {
+        let candidates = decode_deployer_calldata(&create_calldata(vec![0x01, 0x02])).unwrap();
+        assert_eq!(candidates, vec![vec![0x01, 0x02]]);
+    }
+
+    #[test]
+    fn errors_on_unknown_selector() {
+        let err = decode_deployer_calldata(&[0xde, 0xad, 0xbe, 0xef]).unwrap_err();
+        assert!(err.to_string().contains("ContractDeployer"));
+    }
+
+    #[test]
+    fn picks_empty_candidate_when_contract_has_no_explicit_constructor() {
+        let contract = DualCompiledContract::default();
+        let candidates = vec![vec![0x01], vec![]];
+        assert_eq!(pick_matching_candidate(candidates, &contract), Some(vec![]));
+    }
+
+    #[test]
+    fn no_match_when_no_candidate_is_empty_and_constructor_is_implicit() {
+        let contract = DualCompiledContract::default();
+        let candidates = vec![vec![0x01, 0x02]];
+        assert_eq!(pick_matching_candidate(candidates, &contract), None);
+    }
+}
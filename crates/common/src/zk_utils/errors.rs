@@ -0,0 +1,85 @@
+//! Typed errors for the `zk_utils` setup path (RPC URL, private key, chain, and signer
+//! resolution), plus a connectivity pre-flight that confirms an endpoint is actually a zkSync
+//! node.
+//!
+//! Before this module existed, a malformed RPC URL or a non-zkSync endpoint would surface as an
+//! opaque panic deep inside a broadcast (`get_url_with_port` returning `None`, callers `unwrap`ing
+//! the rest of the setup path). These are the well-typed, user-actionable errors that should be
+//! returned instead, so the CLI can print them and exit cleanly.
+
+use reqwest::Client;
+use serde_json::json;
+
+/// Errors surfaced while resolving zkSync RPC connection details or building a signer.
+#[derive(Debug, thiserror::Error)]
+pub enum ZkSetupError {
+    /// No RPC URL was provided via `--rpc-url`/`ETH_RPC_URL`.
+    #[error(
+        "RPC URL was not provided. Try using --rpc-url flag or environment variable 'ETH_RPC_URL='"
+    )]
+    MissingRpcUrl,
+    /// The provided RPC URL string could not be parsed, or has no host.
+    #[error("invalid RPC URL `{0}`")]
+    InvalidRpcUrl(String),
+    /// The endpoint did not respond to a zkSync-only RPC method (`zks_L1ChainId`), so it is most
+    /// likely not a zkSync node.
+    #[error(
+        "`{0}` does not appear to be a zkSync node (it did not respond to zks_L1ChainId): {1}"
+    )]
+    NotZkSyncEndpoint(String, String),
+    /// The RPC URL could not be reached at all.
+    #[error("could not reach RPC URL `{0}`: {1}")]
+    Unreachable(String, String),
+    /// No private key was provided via `--private-key`.
+    #[error("private key was not provided. Try using --private-key flag")]
+    MissingPrivateKey,
+    /// The provided private key string could not be parsed as 32 bytes of hex.
+    #[error("error parsing private key: {0}")]
+    InvalidPrivateKey(String),
+    /// No chain was provided via `--chain`/`CHAIN`.
+    #[error(
+        "chain was not provided. Use --chain flag (ex. --chain 270) \nor environment variable 'CHAIN=' (ex. 'CHAIN=270')"
+    )]
+    MissingChain,
+    /// A signer could not be built from the resolved private key/chain.
+    #[error("could not create signer: {0}")]
+    InvalidSigner(String),
+}
+
+/// Confirms that `rpc_url` responds to a zkSync-only RPC method (`zks_L1ChainId`), so a
+/// misconfigured or non-zkSync endpoint is caught locally with an actionable error instead of
+/// failing deep inside a broadcast.
+pub async fn preflight_check(rpc_url: &str) -> Result<(), ZkSetupError> {
+    let client = Client::new();
+    let res = client
+        .post(rpc_url)
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "zks_L1ChainId",
+            "params": [],
+        }))
+        .send()
+        .await
+        .map_err(|e| ZkSetupError::Unreachable(rpc_url.to_string(), e.to_string()))?;
+
+    let body: serde_json::Value = res
+        .json()
+        .await
+        .map_err(|e| ZkSetupError::NotZkSyncEndpoint(rpc_url.to_string(), e.to_string()))?;
+
+    if body.get("result").is_none() {
+        let message = body
+            .get("error")
+            .and_then(|e| e.get("message"))
+            .and_then(|m| m.as_str())
+            .unwrap_or("no result field in response")
+            .to_string();
+        return Err(ZkSetupError::NotZkSyncEndpoint(
+            rpc_url.to_string(),
+            message,
+        ));
+    }
+
+    Ok(())
+}
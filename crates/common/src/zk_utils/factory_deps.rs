@@ -0,0 +1,29 @@
+//! Tools for working with factory deps.
+//!
+//! zkSync contracts that deploy other contracts at runtime must declare the bytecode of every
+//! contract they may deploy as a "factory dependency" alongside the deploy transaction, since the
+//! sequencer has no other way to resolve a bytecode hash it has never seen to its bytecode.
+
+use crate::zk_utils::DualCompiledContract;
+use eyre::{eyre, Result};
+use std::collections::HashMap;
+use zksync_types::H256;
+
+/// Looks up the deployed bytecode for each of `required_bytecode_hashes` in `registry`, in the
+/// order they're given, so it can be attached to a deploy transaction as `factory_deps`.
+pub fn resolve_factory_deps(
+    required_bytecode_hashes: &[H256],
+    registry: &HashMap<H256, DualCompiledContract>,
+) -> Result<Vec<Vec<u8>>> {
+    required_bytecode_hashes
+        .iter()
+        .map(|hash| {
+            registry
+                .get(hash)
+                .map(|contract| contract.zk_deployed_bytecode.clone())
+                .ok_or_else(|| {
+                    eyre!("no dual-compiled contract found for factory dependency {hash:?}")
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,100 @@
+//! Live zkSync fee estimation via `zks_estimateFee`.
+//!
+//! [`fix_l2_gas_price`] and [`fix_l2_gas_limit`] are floor/ceiling placeholders that don't track
+//! the network's actual fee market: scripts can run out of balance when the base fee rises above
+//! the floor, and tests can underprovision gas when the ceiling clips a legitimately larger
+//! estimate. This module asks the node directly via its `zks_estimateFee` JSON-RPC method instead,
+//! and only falls back to the magic values if that RPC call itself is unavailable.
+
+use crate::zk_utils::{fix_l2_gas_limit, fix_l2_gas_price, get_rpc_url};
+use eyre::Result;
+use serde::{Deserialize, Serialize};
+use zksync_basic_types::U256;
+use zksync_web3_rs::eip712::Eip712TransactionRequest;
+use zksync_web3_rs::providers::{Http, Middleware, Provider};
+
+/// Mirrors zkSync's own default for `gas_per_pubdata_limit`, used only when `zks_estimateFee`
+/// can't be reached; the RPC's own answer is always preferred.
+const FALLBACK_GAS_PER_PUBDATA_LIMIT: u64 = 800;
+
+/// Fee parameters estimated for a prepared transaction by `zks_estimateFee`.
+///
+/// `gas_per_pubdata_limit` in particular must be threaded through to the signer: zkSync
+/// transactions are rejected without it, unlike plain EVM transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkFeeEstimate {
+    /// Gas limit the transaction should be sent with.
+    pub gas_limit: U256,
+    /// `maxFeePerGas`, EIP-1559 style.
+    pub max_fee_per_gas: U256,
+    /// `maxPriorityFeePerGas`, EIP-1559 style.
+    pub max_priority_fee_per_gas: U256,
+    /// Gas to reserve per byte of pubdata the transaction publishes.
+    pub gas_per_pubdata_limit: U256,
+}
+
+impl ZkFeeEstimate {
+    /// The floor/ceiling fallback used when `zks_estimateFee` can't be reached.
+    fn fallback(gas_price: U256, gas_limit: U256) -> Self {
+        Self {
+            gas_limit: fix_l2_gas_limit(gas_limit),
+            max_fee_per_gas: fix_l2_gas_price(gas_price),
+            max_priority_fee_per_gas: fix_l2_gas_price(gas_price),
+            gas_per_pubdata_limit: U256::from(FALLBACK_GAS_PER_PUBDATA_LIMIT),
+        }
+    }
+}
+
+/// Estimates fee parameters for `tx` against the node selected by `rpc_url`, falling back to the
+/// [`fix_l2_gas_price`]/[`fix_l2_gas_limit`] magic values only if `zks_estimateFee` is
+/// unavailable.
+///
+/// `tx` must be an [`Eip712TransactionRequest`] rather than a plain EVM transaction: a
+/// deployment's `factory_deps` are required *input* to the estimate (the node needs the actual
+/// bytecode behind the hash the calldata references to price pubdata/gas), not output being
+/// estimated, and only the EIP-712 request type carries them.
+pub async fn estimate_fee(
+    rpc_url: &Option<String>,
+    tx: &Eip712TransactionRequest,
+    fallback_gas_price: U256,
+    fallback_gas_limit: U256,
+) -> Result<ZkFeeEstimate> {
+    let rpc_url = get_rpc_url(rpc_url)?;
+    let provider = Provider::<Http>::try_from(rpc_url.as_str())?;
+
+    match provider
+        .request::<_, ZkFeeEstimate>("zks_estimateFee", [tx])
+        .await
+    {
+        Ok(estimate) => Ok(estimate),
+        Err(err) => {
+            tracing::warn!(
+                error = %err,
+                "zks_estimateFee was unavailable, falling back to the static gas price/limit"
+            );
+            Ok(ZkFeeEstimate::fallback(
+                fallback_gas_price,
+                fallback_gas_limit,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_clamps_gas_price_and_limit() {
+        let estimate = ZkFeeEstimate::fallback(U256::from(1), U256::from(u32::MAX));
+
+        assert_eq!(estimate.max_fee_per_gas, U256::from(260_000_000));
+        assert_eq!(estimate.max_priority_fee_per_gas, U256::from(260_000_000));
+        assert_eq!(estimate.gas_limit, U256::from(u32::MAX >> 1));
+        assert_eq!(
+            estimate.gas_per_pubdata_limit,
+            U256::from(FALLBACK_GAS_PER_PUBDATA_LIMIT)
+        );
+    }
+}
@@ -9,35 +9,48 @@ use alloy_primitives::B256;
 ///
 /// Functions in this module:
 ///
-/// - `get_rpc_url`: Retrieves the RPC URL for Ethereum. Returns `Result<String>` with the RPC
-///   URL if successful, or an error message if the RPC URL was not provided.
+/// - `get_rpc_url`: Retrieves the RPC URL for Ethereum. Returns `Result<String, ZkSetupError>`
+///   with the RPC URL if successful, or a typed error if it was missing or invalid.
 ///
 /// - `get_url_with_port`: Parses a URL string and attaches a default port if one is not
-///   specified. Returns an `Option<String>` with the parsed URL if successful, or `None` if
-///   the input was not a valid URL.
+///   specified. Returns `Result<String, ZkSetupError>` with the parsed URL if successful, or
+///   `Err(ZkSetupError::InvalidRpcUrl(..))` if the input was not a valid URL.
 ///
-/// - `get_private_key`: Gets the private key from the Ethereum options. Returns `Result<H256>`
-///   with the private key as `H256` if successful, or an error message if the private key was
-///   not provided.
+/// - `get_private_key`: Gets the private key from the Ethereum options. Returns
+///   `Result<H256, ZkSetupError>` with the private key as `H256` if successful, or a typed error
+///   if the private key was missing or invalid.
 ///
-/// - `get_chain`: Gets the chain from the Ethereum options. Returns `Result<Chain>` with the
-///   chain configuration if successful, or an error message if the chain was not provided.
+/// - `get_chain`: Gets the chain from the Ethereum options. Returns `Result<Chain, ZkSetupError>`
+///   with the chain configuration if successful, or a typed error if the chain was not provided.
 ///
-/// - `get_signer`: Creates a signer from the private key and the chain. Returns a
-///   `Signer<PrivateKeySigner>` instance for signing transactions on the zkSync network.
-///
-/// - `decode_hex`: Decodes a hexadecimal string into a byte vector. Returns `Result<Vec<u8>>`
-///   with the decoded byte vector if successful, or a `ParseIntError` if the decoding fails.
+/// - `get_signer`: Pings the RPC URL to confirm it is a zkSync node, then creates a signer from
+///   the private key and the chain. Returns a `Signer<PrivateKeySigner>` instance for signing
+///   transactions on the zkSync network, or a [`ZkSetupError`] describing what went wrong.
 use eyre::Result;
 use foundry_config::Chain;
 use url::Url;
 use zksync_basic_types::U256;
+use zksync_web3_rs::signers::{PrivateKeySigner, Signer};
 use zksync_web3_rs::types::H256;
 
+/// Routes simulated `forge script` broadcasts through the zkSync signer
+pub mod broadcast;
+/// Validity and size checks for zksolc-compiled bytecode
+pub mod bytecode;
+/// Recovery of constructor arguments for `--guess-constructor-args`
+pub mod constructor_args;
 /// Utils for conversion between zksync types and revm types
 pub mod conversion_utils;
+/// Typed errors for the `zk_utils` setup path, plus a zkSync connectivity pre-flight
+pub mod errors;
+/// Live `zks_estimateFee` fee estimation
+pub mod fee;
 /// Tools for working with factory deps
 pub mod factory_deps;
+/// zkSync block explorer contract verification
+pub mod verify;
+
+pub use errors::ZkSetupError;
 
 /// Gets the RPC URL for Ethereum.
 ///
@@ -47,22 +60,18 @@ pub mod factory_deps;
 ///
 /// A `Result` which is:
 /// - Ok: Contains the RPC URL as a String.
-/// - Err: Contains an error message indicating that the RPC URL was not provided.
-pub fn get_rpc_url(rpc_url: &Option<String>) -> eyre::Result<String> {
+/// - Err: A [`ZkSetupError`] indicating that the RPC URL was not provided or was invalid.
+pub fn get_rpc_url(rpc_url: &Option<String>) -> Result<String, ZkSetupError> {
     match rpc_url {
-            Some(url) => {
-                let rpc_url = get_url_with_port(url)
-                    .ok_or_else(|| eyre::Report::msg("Invalid RPC_URL"))?;
-                Ok(rpc_url)
-            },
-            None => Err(eyre::Report::msg("RPC URL was not provided. Try using --rpc-url flag or environment variable 'ETH_RPC_URL= '")),
-        }
+        Some(url) => get_url_with_port(url),
+        None => Err(ZkSetupError::MissingRpcUrl),
+    }
 }
 
 /// Parses a URL string and attaches a default port if one is not specified.
 ///
 /// This function takes a URL string as input and attempts to parse it.
-/// If the URL string is not a valid URL, the function returns `None`.
+/// If the URL string is not a valid URL, a [`ZkSetupError::InvalidRpcUrl`] is returned.
 /// If the URL is valid and has a specified port, the function returns the URL as is.
 /// If the URL is valid but does not have a specified port, the function attaches a default
 /// port. The default port is 443 if the URL uses the HTTPS scheme, and 80 otherwise.
@@ -73,13 +82,15 @@ pub fn get_rpc_url(rpc_url: &Option<String>) -> eyre::Result<String> {
 ///
 /// # Returns
 ///
-/// An `Option` which contains a String with the parsed URL if successful, or `None` if the
-/// input was not a valid URL.
-pub fn get_url_with_port(url_str: &str) -> Option<String> {
-    let url = Url::parse(url_str).ok()?;
+/// A `Result` which contains a String with the parsed URL if successful, or a
+/// [`ZkSetupError::InvalidRpcUrl`] if the input was not a valid URL.
+pub fn get_url_with_port(url_str: &str) -> Result<String, ZkSetupError> {
+    let invalid = || ZkSetupError::InvalidRpcUrl(url_str.to_string());
+    let url = Url::parse(url_str).map_err(|_| invalid())?;
+    let host = url.host_str().ok_or_else(invalid)?;
     let default_port = url.scheme() == "https" && url.port().is_none();
     let port = url.port().unwrap_or(if default_port { 443 } else { 80 });
-    Some(format!("{}://{}:{}{}", url.scheme(), url.host_str()?, port, url.path()))
+    Ok(format!("{}://{}:{}{}", url.scheme(), host, port, url.path()))
 }
 
 /// Gets the private key from the Ethereum options.
@@ -90,17 +101,20 @@ pub fn get_url_with_port(url_str: &str) -> Option<String> {
 ///
 /// A `Result` which is:
 /// - Ok: Contains the private key as `H256`.
-/// - Err: Contains an error message indicating that the private key was not provided.
-pub fn get_private_key(private_key: &Option<String>) -> Result<H256> {
+/// - Err: A [`ZkSetupError`] indicating that the private key was not provided or was invalid.
+pub fn get_private_key(private_key: &Option<String>) -> Result<H256, ZkSetupError> {
     match private_key {
         Some(pkey) => {
-            let val = hex::decode(pkey)
-                .map_err(|e| eyre::Report::msg(format!("Error parsing private key: {}", e)))?;
+            let val = hex::decode(pkey).map_err(|e| ZkSetupError::InvalidPrivateKey(e.to_string()))?;
+            if val.len() != 32 {
+                return Err(ZkSetupError::InvalidPrivateKey(format!(
+                    "expected 32 bytes, got {}",
+                    val.len()
+                )));
+            }
             Ok(H256::from_slice(&val))
         }
-        None => {
-            Err(eyre::Report::msg("Private key was not provided. Try using --private-key flag"))
-        }
+        None => Err(ZkSetupError::MissingPrivateKey),
     }
 }
 
@@ -112,14 +126,36 @@ pub fn get_private_key(private_key: &Option<String>) -> Result<H256> {
 ///
 /// A `Result` which is:
 /// - Ok: Contains the chain as `Chain`.
-/// - Err: Contains an error message indicating that the chain was not provided.
-pub fn get_chain(chain: Option<Chain>) -> Result<Chain> {
-    match chain {
-            Some(chain) => Ok(chain),
-            None => Err(eyre::Report::msg(
-                "Chain was not provided. Use --chain flag (ex. --chain 270 ) \nor environment variable 'CHAIN= ' (ex.'CHAIN=270')",
-            )),
-        }
+/// - Err: A [`ZkSetupError::MissingChain`] indicating that the chain was not provided.
+pub fn get_chain(chain: Option<Chain>) -> Result<Chain, ZkSetupError> {
+    chain.ok_or(ZkSetupError::MissingChain)
+}
+
+/// Creates a signer from the private key and the chain.
+///
+/// Before building the signer, pings `rpc_url` and confirms it responds to a zkSync-only RPC
+/// method (`zks_L1ChainId`), so a malformed URL or an RPC that is not actually a zkSync node is
+/// caught here with an actionable error instead of panicking mid-broadcast.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - Ok: Contains a `Signer<PrivateKeySigner>` instance for signing transactions on the zkSync
+///   network.
+/// - Err: A [`ZkSetupError`] indicating which part of the setup failed.
+pub async fn get_signer(
+    private_key: &Option<String>,
+    chain: Option<Chain>,
+    rpc_url: &Option<String>,
+) -> Result<Signer<PrivateKeySigner>, ZkSetupError> {
+    let resolved_rpc_url = get_rpc_url(rpc_url)?;
+    errors::preflight_check(&resolved_rpc_url).await?;
+
+    let private_key = get_private_key(private_key)?;
+    let chain = get_chain(chain)?;
+    let wallet = PrivateKeySigner::from_bytes(private_key.as_bytes())
+        .map_err(|e| ZkSetupError::InvalidSigner(e.to_string()))?;
+    Ok(Signer::new(wallet, chain.id()))
 }
 
 /// Fixes the gas price to be minimum of 0.26GWei which is above the block base fee on L2.
@@ -142,6 +178,79 @@ pub fn fix_l2_gas_limit(gas_limit: U256) -> U256 {
     U256::min(gas_limit, U256::from(u32::MAX >> 1))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_with_port_attaches_default_https_port() {
+        assert_eq!(
+            get_url_with_port("https://example.com").unwrap(),
+            "https://example.com:443/"
+        );
+    }
+
+    #[test]
+    fn url_with_port_attaches_default_http_port() {
+        assert_eq!(
+            get_url_with_port("http://example.com").unwrap(),
+            "http://example.com:80/"
+        );
+    }
+
+    #[test]
+    fn url_with_port_keeps_explicit_port() {
+        assert_eq!(
+            get_url_with_port("http://example.com:1234").unwrap(),
+            "http://example.com:1234/"
+        );
+    }
+
+    #[test]
+    fn url_with_port_rejects_invalid_url() {
+        assert!(matches!(
+            get_url_with_port("not a url"),
+            Err(ZkSetupError::InvalidRpcUrl(_))
+        ));
+    }
+
+    #[test]
+    fn rpc_url_errors_when_missing() {
+        assert!(matches!(get_rpc_url(&None), Err(ZkSetupError::MissingRpcUrl)));
+    }
+
+    #[test]
+    fn private_key_errors_when_missing() {
+        assert!(matches!(
+            get_private_key(&None),
+            Err(ZkSetupError::MissingPrivateKey)
+        ));
+    }
+
+    #[test]
+    fn private_key_errors_on_invalid_hex() {
+        assert!(matches!(
+            get_private_key(&Some("not hex".to_string())),
+            Err(ZkSetupError::InvalidPrivateKey(_))
+        ));
+    }
+
+    #[test]
+    fn chain_errors_when_missing() {
+        assert!(matches!(get_chain(None), Err(ZkSetupError::MissingChain)));
+    }
+
+    #[test]
+    fn fix_l2_gas_price_floors_low_values() {
+        assert_eq!(fix_l2_gas_price(U256::from(1)), U256::from(260_000_000));
+    }
+
+    #[test]
+    fn fix_l2_gas_limit_caps_high_values() {
+        assert_eq!(fix_l2_gas_limit(U256::from(u32::MAX)), U256::from(u32::MAX >> 1));
+    }
+}
+
 /// Defines a contract that has been dual compiled with both zksolc and solc
 #[derive(Debug, Default, Clone)]
 pub struct DualCompiledContract {
@@ -157,4 +266,6 @@ pub struct DualCompiledContract {
     pub evm_deployed_bytecode: Vec<u8>,
     /// Bytecode with solc
     pub evm_bytecode: Vec<u8>,
+    /// The contract's ABI, used e.g. to decode constructor arguments
+    pub abi: alloy_json_abi::JsonAbi,
 }
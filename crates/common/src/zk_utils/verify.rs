@@ -0,0 +1,265 @@
+//! zkSync contract verification against a block explorer.
+//!
+//! Unlike EVM verification (handled by `forge verify-contract` against Etherscan-like APIs),
+//! zkSync contracts are compiled twice (see [`DualCompiledContract`]) and must be verified
+//! against the zkSync block explorer's own verification API, which expects the zksolc compiler
+//! settings that actually produced the deployed bytecode rather than solc's.
+
+use crate::zk_utils::{
+    constructor_args::guess_constructor_args, get_chain, get_rpc_url, DualCompiledContract,
+};
+use alloy_primitives::Address;
+use eyre::{eyre, Result};
+use foundry_config::Chain;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use zksync_utils::bytecode::hash_bytecode;
+use zksync_web3_rs::providers::{Http, Middleware, Provider};
+use zksync_web3_rs::types::H256;
+
+/// The two submission strategies supported by the zkSync explorer verification API.
+///
+/// Mirrors the "smarter verification" fallback Foundry already uses for EVM chains: a
+/// standard-JSON-input submission is attempted first since it reproduces the exact compiler
+/// settings used to build the contract, and a flattened single-file submission is only used if
+/// the explorer rejects it (e.g. because it does not support standard-json for that compiler
+/// version).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZkVerificationStrategy {
+    /// Submit the full standard-JSON-input, including the exact zksolc settings used to produce
+    /// `zk_bytecode_hash`.
+    StandardJsonInput,
+    /// Submit a single flattened source file. Used as a fallback when standard-json is rejected.
+    Flattened,
+}
+
+/// A request to verify a deployed zkSync contract against a block explorer.
+#[derive(Debug, Clone)]
+pub struct ZkVerifyRequest {
+    /// Address the contract was deployed to.
+    pub address: Address,
+    /// The dual-compiled contract being verified.
+    pub contract: DualCompiledContract,
+    /// Standard-JSON-input payload produced for the zksolc compilation, if available.
+    pub standard_json: Option<Value>,
+    /// Flattened single-file source, used for the fallback submission.
+    pub flattened_source: String,
+    /// ABI-encoded constructor arguments, if any.
+    pub constructor_args: Option<Vec<u8>>,
+    /// zksolc version used to produce `zk_bytecode_hash`.
+    pub zksolc_version: String,
+}
+
+/// Payload submitted to the explorer's `/contract_verification` endpoint.
+#[derive(Debug, Clone, Serialize)]
+struct ZkVerificationPayload {
+    #[serde(rename = "contractAddress")]
+    contract_address: Address,
+    #[serde(rename = "codeFormat")]
+    code_format: &'static str,
+    #[serde(rename = "sourceCode")]
+    source_code: Value,
+    #[serde(rename = "contractName")]
+    contract_name: String,
+    #[serde(rename = "zkCompilerVersion")]
+    zk_compiler_version: String,
+    #[serde(rename = "constructorArguments")]
+    constructor_arguments: String,
+}
+
+/// Response returned by the zkSync explorer after a successful verification submission.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ZkVerificationSubmission {
+    /// Identifier the explorer assigned to track the verification request.
+    #[serde(rename = "verificationId")]
+    pub verification_id: u64,
+}
+
+/// Client for submitting contract verification requests to a zkSync block explorer.
+#[derive(Debug, Clone)]
+pub struct ZkExplorerClient {
+    http: reqwest::Client,
+    explorer_api_url: String,
+    rpc_url: String,
+}
+
+impl ZkExplorerClient {
+    /// Builds a client targeting the verification API of the zkSync explorer matching `chain`,
+    /// using `rpc_url`/`chain` to select the network the same way the rest of the zkSync tooling
+    /// does (see [`get_rpc_url`] and [`get_chain`]).
+    pub fn new(rpc_url: &Option<String>, chain: Option<Chain>) -> Result<Self> {
+        let rpc_url = get_rpc_url(rpc_url)?;
+        let chain = get_chain(chain)?;
+        let explorer_api_url = zk_explorer_api_url(chain)?;
+        Ok(Self {
+            http: reqwest::Client::new(),
+            explorer_api_url,
+            rpc_url,
+        })
+    }
+
+    /// Verifies that the bytecode currently deployed at `request.address` matches
+    /// `request.contract.zk_bytecode_hash` before attempting a remote submission, so a mismatch
+    /// produces a clear local error instead of a confusing explorer-side rejection.
+    pub async fn check_deployed_bytecode_hash(&self, request: &ZkVerifyRequest) -> Result<()> {
+        let provider = Provider::<Http>::try_from(self.rpc_url.as_str())?;
+        let code = provider
+            .get_code(ethers::types::H160::from(request.address.0 .0), None)
+            .await?;
+        if code.0.is_empty() {
+            return Err(eyre!("no contract deployed at {}", request.address));
+        }
+
+        let onchain_hash = hash_bytecode(&code);
+        if onchain_hash != request.contract.zk_bytecode_hash {
+            return Err(eyre!(
+                "deployed bytecode hash {onchain_hash:?} does not match the zksolc bytecode \
+                 hash {:?} recorded for `{}`; verifying would fail on the explorer",
+                request.contract.zk_bytecode_hash,
+                request.contract.name
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Fills in `request.constructor_args` by recovering them from the contract's creation
+    /// transaction, for use with `--guess-constructor-args` instead of requiring the user to
+    /// pass `--constructor-args` explicitly. No-op if `request.constructor_args` is already set.
+    pub async fn guess_constructor_args(
+        &self,
+        request: &mut ZkVerifyRequest,
+        creation_tx_hash: H256,
+    ) -> Result<()> {
+        if request.constructor_args.is_some() {
+            return Ok(());
+        }
+
+        let args = guess_constructor_args(
+            &Some(self.rpc_url.clone()),
+            creation_tx_hash,
+            &request.contract,
+        )
+        .await?
+        .ok_or_else(|| {
+            eyre!(
+                "could not recover constructor arguments for `{}` from {creation_tx_hash:?}; \
+                         pass --constructor-args explicitly",
+                request.contract.name
+            )
+        })?;
+
+        request.constructor_args = Some(args);
+        Ok(())
+    }
+
+    /// Submits `request` for verification, trying a standard-JSON-input submission first and
+    /// falling back to a flattened single-file submission if the explorer rejects it.
+    pub async fn verify(
+        &self,
+        request: &ZkVerifyRequest,
+    ) -> Result<(ZkVerificationStrategy, ZkVerificationSubmission)> {
+        self.check_deployed_bytecode_hash(request).await?;
+
+        if let Some(standard_json) = &request.standard_json {
+            let payload = self.payload(
+                request,
+                standard_json.clone(),
+                "solidity-standard-json-input",
+            );
+            match self.submit(&payload).await {
+                Ok(submission) => {
+                    return Ok((ZkVerificationStrategy::StandardJsonInput, submission))
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        contract = %request.contract.name,
+                        error = %err,
+                        "standard-json-input verification was rejected, falling back to flattened source"
+                    );
+                }
+            }
+        }
+
+        let payload = self.payload(
+            request,
+            Value::String(request.flattened_source.clone()),
+            "solidity-single-file",
+        );
+        let submission = self.submit(&payload).await?;
+        Ok((ZkVerificationStrategy::Flattened, submission))
+    }
+
+    fn payload(
+        &self,
+        request: &ZkVerifyRequest,
+        source_code: Value,
+        code_format: &'static str,
+    ) -> ZkVerificationPayload {
+        ZkVerificationPayload {
+            contract_address: request.address,
+            code_format,
+            source_code,
+            contract_name: request.contract.name.clone(),
+            zk_compiler_version: request.zksolc_version.clone(),
+            constructor_arguments: format!(
+                "0x{}",
+                hex::encode(request.constructor_args.clone().unwrap_or_default())
+            ),
+        }
+    }
+
+    async fn submit(&self, payload: &ZkVerificationPayload) -> Result<ZkVerificationSubmission> {
+        let res = self
+            .http
+            .post(format!("{}/contract_verification", self.explorer_api_url))
+            .json(payload)
+            .send()
+            .await?;
+
+        if !res.status().is_success() {
+            let status = res.status();
+            let body = res.text().await.unwrap_or_default();
+            return Err(eyre!(
+                "zkSync explorer rejected verification ({status}): {body}"
+            ));
+        }
+
+        Ok(res.json().await?)
+    }
+}
+
+/// Resolves the verification API base URL of the zkSync block explorer for `chain`.
+fn zk_explorer_api_url(chain: Chain) -> Result<String> {
+    match chain.id() {
+        324 => Ok("https://zksync2-mainnet-explorer.zksync.io".to_string()),
+        300 => Ok("https://block-explorer-api.sepolia.zksync.dev".to_string()),
+        _ => Err(eyre!(
+            "no known zkSync block explorer for chain {chain}; only zkSync mainnet (324) and \
+             Sepolia (300) are currently supported"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_known_chains() {
+        assert_eq!(
+            zk_explorer_api_url(Chain::from(324)).unwrap(),
+            "https://zksync2-mainnet-explorer.zksync.io"
+        );
+        assert_eq!(
+            zk_explorer_api_url(Chain::from(300)).unwrap(),
+            "https://block-explorer-api.sepolia.zksync.dev"
+        );
+    }
+
+    #[test]
+    fn errors_on_unknown_chain() {
+        let err = zk_explorer_api_url(Chain::from(1)).unwrap_err();
+        assert!(err.to_string().contains("no known zkSync block explorer"));
+    }
+}
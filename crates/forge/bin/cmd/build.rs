@@ -2,7 +2,10 @@ use super::{install, watch::WatchArgs};
 use clap::Parser;
 use eyre::Result;
 use foundry_cli::{opts::CoreBuildArgs, utils::LoadConfig};
-use foundry_common::compile::{ProjectCompiler, SkipBuildFilter, SkipBuildFilters};
+use foundry_common::{
+    compile::{ProjectCompiler, SkipBuildFilter, SkipBuildFilters},
+    zk_utils::bytecode::{check_bytecode_size, validate_bytecode_words, ZkContractBytecodeSize},
+};
 use foundry_compilers::{Project, ProjectCompileOutput};
 use foundry_config::{
     figment::{
@@ -79,8 +82,8 @@ impl BuildArgs {
         let mut config = self.try_load_config_emit_warnings()?;
         let mut project = config.project()?;
 
-        if install::install_missing_dependencies(&mut config, self.args.silent) &&
-            config.auto_detect_remappings
+        if install::install_missing_dependencies(&mut config, self.args.silent)
+            && config.auto_detect_remappings
         {
             // need to re-configure here to also catch additional remappings
             config = self.load_config();
@@ -100,7 +103,10 @@ impl BuildArgs {
         let output = compiler.compile(&project)?;
 
         if self.format_json {
-            println!("{}", serde_json::to_string_pretty(&output.clone().output())?);
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&output.clone().output())?
+            );
         }
 
         if config.zksync {
@@ -110,8 +116,39 @@ impl BuildArgs {
                 .quiet(self.format_json)
                 .bail(!self.format_json);
             let zk_output = zk_compiler.zksync_compile(&project)?;
+
+            let mut zk_sizes = Vec::new();
+            for (name, artifact) in zk_output.artifacts() {
+                let Some(deployed_bytecode) = artifact.get_deployed_bytecode_bytes() else {
+                    continue;
+                };
+                validate_bytecode_words(&name, &deployed_bytecode)?;
+                zk_sizes.push(check_bytecode_size(&name, &deployed_bytecode));
+            }
+
+            if !self.format_json {
+                for size in zk_sizes.iter().filter(|size| size.size_exceeded) {
+                    tracing::warn!(
+                        "`{}` deployed bytecode ({} bytes) exceeds the zkSync size limit of {} bytes",
+                        size.name,
+                        size.size,
+                        foundry_common::zk_utils::bytecode::MAX_ZKSYNC_BYTECODE_SIZE
+                    );
+                }
+
+                if self.sizes {
+                    print_zk_sizes(&zk_sizes);
+                }
+            }
+
             if self.format_json {
-                println!("{}", serde_json::to_string_pretty(&zk_output.clone().output())?);
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&zk_output.clone().output())?
+                );
+                if self.sizes {
+                    println!("{}", serde_json::to_string_pretty(&zk_sizes)?);
+                }
             }
         }
 
@@ -143,6 +180,27 @@ impl BuildArgs {
     }
 }
 
+/// Prints a table of zkSync deployed bytecode sizes, flagging any contract that exceeds
+/// [`foundry_common::zk_utils::bytecode::MAX_ZKSYNC_BYTECODE_SIZE`].
+fn print_zk_sizes(sizes: &[ZkContractBytecodeSize]) {
+    let mut table = comfy_table::Table::new();
+    table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+    table.set_header(["Contract", "Size (zkSync, bytes)", "Margin"]);
+
+    for size in sizes {
+        let margin = foundry_common::zk_utils::bytecode::MAX_ZKSYNC_BYTECODE_SIZE as isize
+            - size.size as isize;
+        let margin = if size.size_exceeded {
+            format!("{margin} (exceeds limit)")
+        } else {
+            margin.to_string()
+        };
+        table.add_row([size.name.clone(), size.size.to_string(), margin]);
+    }
+
+    println!("{table}");
+}
+
 // Make this args a `figment::Provider` so that it can be merged into the `Config`
 impl Provider for BuildArgs {
     fn metadata(&self) -> Metadata {
@@ -180,10 +238,16 @@ mod tests {
 
         let args: BuildArgs =
             BuildArgs::parse_from(["foundry-cli", "--skip", "tests", "--skip", "scripts"]);
-        assert_eq!(args.skip, Some(vec![SkipBuildFilter::Tests, SkipBuildFilter::Scripts]));
+        assert_eq!(
+            args.skip,
+            Some(vec![SkipBuildFilter::Tests, SkipBuildFilter::Scripts])
+        );
 
         let args: BuildArgs = BuildArgs::parse_from(["foundry-cli", "--skip", "tests", "scripts"]);
-        assert_eq!(args.skip, Some(vec![SkipBuildFilter::Tests, SkipBuildFilter::Scripts]));
+        assert_eq!(
+            args.skip,
+            Some(vec![SkipBuildFilter::Tests, SkipBuildFilter::Scripts])
+        );
     }
 
     #[test]